@@ -0,0 +1,10 @@
+// Compiles `proto/dialogchain/ingest.proto` into the `dialogchain.ingest`
+// module `tonic::include_proto!` pulls in from main.rs. Uses the vendored
+// `protoc` binary rather than requiring one on $PATH, since build hosts
+// (and this repo's CI image) don't all ship it.
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    println!("cargo:rerun-if-changed=proto/dialogchain/ingest.proto");
+    tonic_build::compile_protos("proto/dialogchain/ingest.proto")?;
+    Ok(())
+}